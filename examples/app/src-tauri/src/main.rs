@@ -82,6 +82,16 @@ fn main() {
                 dbg!(event.payload);
             });
 
+            struct LoggingTransport;
+
+            impl tauri_specta::EventTransport for LoggingTransport {
+                fn send(&self, name: &str, payload: serde_json::Value) {
+                    dbg!(name, payload);
+                }
+            }
+
+            tauri_specta::register_event_transport(&handle, LoggingTransport);
+
             DemoEvent("Test".to_string()).emit_all(&handle).ok();
 
             EmptyEvent::listen_any(&handle, {
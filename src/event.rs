@@ -1,6 +1,9 @@
 use std::{
     collections::{BTreeMap, BTreeSet},
-    sync::RwLock,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, RwLock,
+    },
 };
 
 use serde::{de::DeserializeOwned, Serialize};
@@ -9,9 +12,49 @@ use tauri::{EventId, Manager, Runtime, Window};
 
 use crate::PluginName;
 
-#[derive(Clone, Copy)]
+/// Holds the most recently emitted payload for an event, so every [`Event::listen_latest`]
+/// caller can be brought up to date, not just the first one after an emit. Emits only keep
+/// it up to date once something has asked for replay (see [`EventInfo::is_replay_wanted`])
+/// or while an [`EventTransport`] is registered.
+#[derive(Default)]
+struct EventInfo {
+    last_value: RwLock<Option<serde_json::Value>>,
+    replay_wanted: AtomicBool,
+}
+
+impl EventInfo {
+    /// Whether some `listen_latest` caller has asked for replay, meaning emits should pay
+    /// the cost of keeping `last_value` up to date. Before that, there's nobody to serve a
+    /// cached value to, so emits skip the work entirely unless an [`EventTransport`] is
+    /// registered (they then compute the value anyway and store it as well).
+    fn is_replay_wanted(&self) -> bool {
+        self.replay_wanted.load(Ordering::SeqCst)
+    }
+
+    fn store(&self, value: serde_json::Value) {
+        *self
+            .last_value
+            .write()
+            .expect("Failed to write EventInfo") = Some(value);
+    }
+
+    /// Returns the last emitted value, if any, and marks this event as wanting replay from
+    /// now on. A single stored slot naturally coalesces racing emits onto the newest
+    /// payload, so there's nothing to "consume" - every caller sees the same value.
+    fn peek_latest(&self) -> Option<serde_json::Value> {
+        self.replay_wanted.store(true, Ordering::SeqCst);
+
+        self.last_value
+            .read()
+            .expect("Failed to read EventInfo")
+            .clone()
+    }
+}
+
+#[derive(Clone)]
 pub struct EventRegistryMeta {
     plugin_name: PluginName,
+    latest: Arc<EventInfo>,
 }
 
 impl EventRegistryMeta {
@@ -36,19 +79,33 @@ impl EventCollection {
     }
 }
 
+/// A destination typed events can be sent to besides the Tauri IPC bus, e.g. a WebSocket
+/// bridge, an in-process bus for tests, or a logging sink. The built-in `Manager`/`Window`
+/// emit calls remain the default delivery path; transports registered via
+/// [`register_event_transport`] additionally receive every emitted payload.
+pub trait EventTransport: Send + Sync {
+    fn send(&self, name: &str, payload: serde_json::Value);
+}
+
 #[derive(Default)]
-pub(crate) struct EventRegistry(pub(crate) RwLock<BTreeMap<SpectaID, EventRegistryMeta>>);
+pub(crate) struct EventRegistry {
+    pub(crate) meta: RwLock<BTreeMap<SpectaID, EventRegistryMeta>>,
+    transports: RwLock<Vec<Arc<dyn EventTransport>>>,
+}
 
 impl EventRegistry {
     pub fn register_collection(&self, collection: EventCollection, plugin_name: PluginName) {
-        let mut registry = self.0.write().expect("Failed to write EventRegistry");
-
-        registry.extend(
-            collection
-                .0
-                .into_iter()
-                .map(|sid| (sid, EventRegistryMeta { plugin_name })),
-        );
+        let mut registry = self.meta.write().expect("Failed to write EventRegistry");
+
+        registry.extend(collection.0.into_iter().map(|sid| {
+            (
+                sid,
+                EventRegistryMeta {
+                    plugin_name,
+                    latest: Arc::new(EventInfo::default()),
+                },
+            )
+        }));
     }
 
     pub fn get_or_manage<R: Runtime>(handle: &impl Manager<R>) -> tauri::State<'_, Self> {
@@ -58,6 +115,37 @@ impl EventRegistry {
 
         handle.state::<Self>()
     }
+
+    /// Register an additional [`EventTransport`] that every subsequent `emit_*` call will
+    /// fan out to, alongside the default Tauri IPC emission.
+    pub(crate) fn register_transport(&self, transport: Arc<dyn EventTransport>) {
+        self.transports
+            .write()
+            .expect("Failed to write EventRegistry transports")
+            .push(transport);
+    }
+
+    fn has_transports(&self) -> bool {
+        !self
+            .transports
+            .read()
+            .expect("Failed to read EventRegistry transports")
+            .is_empty()
+    }
+
+    fn fanout(&self, name: &str, payload: &serde_json::Value) {
+        // Clone the list out from under the lock so a slow or reentrant `send` can't
+        // hold up other emitters or deadlock against `register_transport`.
+        let transports = self
+            .transports
+            .read()
+            .expect("Failed to read EventRegistry transports")
+            .clone();
+
+        for transport in transports.iter() {
+            transport.send(name, payload.clone());
+        }
+    }
 }
 
 pub struct TypedEvent<T: Event> {
@@ -65,6 +153,47 @@ pub struct TypedEvent<T: Event> {
     pub payload: T,
 }
 
+/// An RAII guard around a listener registered via [`Event::listen_scoped`] or
+/// [`Event::listen_any_scoped`]. The listener is unregistered when this guard is dropped,
+/// so it doesn't need to be torn down manually.
+pub struct ListenGuard<R: Runtime> {
+    id: EventId,
+    handle: tauri::AppHandle<R>,
+}
+
+impl<R: Runtime> ListenGuard<R> {
+    pub fn id(&self) -> EventId {
+        self.id
+    }
+}
+
+impl<R: Runtime> Drop for ListenGuard<R> {
+    fn drop(&mut self) {
+        self.handle.unlisten(self.id);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum EventError {
+    /// The raw event payload was not valid JSON.
+    PayloadParse(String),
+    /// The payload was valid JSON but didn't match the event's type.
+    DeserializationError(String),
+}
+
+impl std::fmt::Display for EventError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventError::PayloadParse(err) => write!(f, "Failed to parse event payload: {err}"),
+            EventError::DeserializationError(err) => {
+                write!(f, "Failed to deserialize event payload: {err}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EventError {}
+
 fn get_meta_from_registry<R: Runtime>(
     sid: SpectaID,
     name: &str,
@@ -73,26 +202,70 @@ fn get_meta_from_registry<R: Runtime>(
     handle.try_state::<EventRegistry>().expect(
         "EventRegistry not found in Tauri state - Did you forget to call Exporter::with_events?",
     )
-    .0
+    .meta
         .read()
         .expect("Failed to read EventRegistry")
         .get(&sid)
-        .copied()
+        .cloned()
         .unwrap_or_else(|| panic!("Event {name} not found in registry!"))
 }
 
+/// Register an [`EventTransport`] that every typed event's `emit_*` call will fan out to,
+/// in addition to the default Tauri IPC emission. This is the reachable entry point for
+/// [`EventRegistry::register_transport`], which is `pub(crate)` since `EventRegistry`
+/// itself isn't part of the public API.
+pub fn register_event_transport<R: Runtime>(
+    handle: &impl Manager<R>,
+    transport: impl EventTransport + 'static,
+) {
+    EventRegistry::get_or_manage(handle).register_transport(Arc::new(transport));
+}
+
+fn fanout_to_transports<R: Runtime>(
+    handle: &impl Manager<R>,
+    name: &str,
+    payload: &impl Serialize,
+) {
+    if let Some(registry) = handle.try_state::<EventRegistry>() {
+        if registry.has_transports() {
+            let payload =
+                serde_json::to_value(payload).expect("Failed to serialize event payload");
+
+            registry.fanout(name, &payload);
+        }
+    }
+}
+
+/// Parses a raw event payload, distinguishing a malformed JSON string from JSON that
+/// parsed fine but doesn't match `T`'s shape.
+fn parse_event_payload<T: DeserializeOwned>(raw: &str) -> Result<T, EventError> {
+    serde_json::from_str::<serde_json::Value>(raw)
+        .map_err(|err| EventError::PayloadParse(err.to_string()))
+        .and_then(|value| {
+            serde_json::from_value(value)
+                .map_err(|err| EventError::DeserializationError(err.to_string()))
+        })
+}
+
 macro_rules! make_handler {
     ($handler:ident) => {
         move |event| {
-            let value: serde_json::Value = serde_json::from_str(event.payload())
-                .ok() // TODO: Error handling
-                .unwrap_or(serde_json::Value::Null);
-
-            $handler(TypedEvent {
+            let result = parse_event_payload::<Self>(event.payload()).map(|payload| TypedEvent {
                 id: event.id(),
-                payload: serde_json::from_value(value)
-                    .expect("Failed to deserialize event payload"),
+                payload,
             });
+
+            $handler(result);
+        }
+    };
+}
+
+macro_rules! make_infallible_handler {
+    ($handler:ident) => {
+        move |result: Result<TypedEvent<Self>, EventError>| {
+            if let Ok(event) = result {
+                $handler(event);
+            }
         }
     };
 }
@@ -103,6 +276,18 @@ macro_rules! get_meta {
     };
 }
 
+/// Whether an emit of this event should pay for computing a `serde_json::Value`: either a
+/// `listen_latest` caller has asked to be kept up to date, or a transport is registered and
+/// needs a payload to fan out. If neither is true, emitting can hand `self` straight to
+/// Tauri and skip the extra serialization entirely.
+fn wants_value_cache<R: Runtime>(handle: &impl Manager<R>, meta: &EventRegistryMeta) -> bool {
+    meta.latest.is_replay_wanted()
+        || handle
+            .try_state::<EventRegistry>()
+            .map(|registry| registry.has_transports())
+            .unwrap_or(false)
+}
+
 pub trait Event: NamedType {
     const NAME: &'static str;
 
@@ -110,72 +295,241 @@ pub trait Event: NamedType {
 
     fn emit_all<R: Runtime>(self, handle: &impl Manager<R>) -> tauri::Result<()>
     where
-        Self: Serialize + Clone,
+        Self: Serialize,
     {
         let meta = get_meta!(handle);
+        let name = meta.wrap_with_plugin(Self::NAME);
+
+        if wants_value_cache(handle, &meta) {
+            let value = serde_json::to_value(&self).expect("Failed to serialize event payload");
+
+            meta.latest.store(value.clone());
+            fanout_to_transports(handle, &name, &value);
 
-        handle.emit(&meta.wrap_with_plugin(Self::NAME), self)
+            // Reuse the value already computed for the replay cache / transport fan-out,
+            // instead of having Tauri serialize `self` a second time internally.
+            handle.emit(&name, value)
+        } else {
+            handle.emit(&name, self)
+        }
     }
 
     fn emit_to<R: Runtime>(self, handle: &impl Manager<R>, label: &str) -> tauri::Result<()>
     where
-        Self: Serialize + Clone,
+        Self: Serialize,
+    {
+        let meta = get_meta!(handle);
+        let name = meta.wrap_with_plugin(Self::NAME);
+
+        if wants_value_cache(handle, &meta) {
+            let value = serde_json::to_value(&self).expect("Failed to serialize event payload");
+
+            meta.latest.store(value.clone());
+            fanout_to_transports(handle, &name, &value);
+
+            handle.emit_to(label, &name, value)
+        } else {
+            handle.emit_to(label, &name, self)
+        }
+    }
+
+    /// Emits to every window for which `filter` returns `true`. `filter` is handed the same
+    /// [`Window`] type used by [`Event::emit`]/[`Event::listen`] throughout this trait, so
+    /// `handle.windows()` is the matching enumerator here; it isn't deprecated for that type
+    /// (the `webview_windows()` alternative some Tauri versions add enumerates a different,
+    /// webview-hosting window type that this crate doesn't otherwise use).
+    fn emit_filter<R: Runtime, F: Fn(&Window<R>) -> bool>(
+        self,
+        handle: &impl Manager<R>,
+        filter: F,
+    ) -> tauri::Result<()>
+    where
+        Self: Serialize,
     {
         let meta = get_meta!(handle);
+        let name = meta.wrap_with_plugin(Self::NAME);
 
-        handle.emit_to(label, &meta.wrap_with_plugin(Self::NAME), self)
+        if wants_value_cache(handle, &meta) {
+            let value = serde_json::to_value(&self).expect("Failed to serialize event payload");
+
+            meta.latest.store(value.clone());
+            fanout_to_transports(handle, &name, &value);
+
+            for window in handle.windows().values().filter(|w| filter(w)) {
+                window.emit(&name, &value)?;
+            }
+        } else {
+            for window in handle.windows().values().filter(|w| filter(w)) {
+                window.emit(&name, &self)?;
+            }
+        }
+
+        Ok(())
     }
 
     fn listen_any<F, R: Runtime>(handle: &impl Manager<R>, handler: F) -> EventId
     where
         F: Fn(TypedEvent<Self>) + Send + 'static,
         Self: DeserializeOwned,
+    {
+        Self::listen_any_result(handle, make_infallible_handler!(handler))
+    }
+
+    fn listen_any_result<F, R: Runtime>(handle: &impl Manager<R>, handler: F) -> EventId
+    where
+        F: Fn(Result<TypedEvent<Self>, EventError>) + Send + 'static,
+        Self: DeserializeOwned,
     {
         let meta = get_meta!(handle);
 
         handle.listen_any(meta.wrap_with_plugin(Self::NAME), make_handler!(handler))
     }
 
+    /// Like [`Event::once_any`], but the `handler` receives a `Result` instead of having
+    /// payloads that fail to parse or deserialize silently dropped.
+    fn once_any_result<F, R: Runtime>(handle: &impl Manager<R>, handler: F)
+    where
+        F: FnOnce(Result<TypedEvent<Self>, EventError>) + Send + 'static,
+        Self: DeserializeOwned,
+    {
+        let meta = get_meta!(handle);
+
+        handle.once_any(meta.wrap_with_plugin(Self::NAME), make_handler!(handler))
+    }
+
+    /// Like [`Event::listen_any`], but the listener is unregistered after firing once. A
+    /// payload that fails to parse or deserialize is silently dropped along with the
+    /// one-shot registration - use [`Event::once_any_result`] if the caller needs to know
+    /// when that happens.
     fn once_any<F, R: Runtime>(handle: &impl Manager<R>, handler: F)
     where
         F: FnOnce(TypedEvent<Self>) + Send + 'static,
         Self: DeserializeOwned,
+    {
+        Self::once_any_result(handle, make_infallible_handler!(handler))
+    }
+
+    /// Stop listening for an event previously registered via [`Event::listen_any`] or
+    /// [`Event::listen`].
+    fn unlisten<R: Runtime>(handle: &impl Manager<R>, id: EventId) {
+        handle.unlisten(id);
+    }
+
+    /// Like [`Event::listen_any`], but returns a [`ListenGuard`] that unregisters the
+    /// listener when dropped, instead of a raw [`EventId`] that must be passed to
+    /// [`Event::unlisten`] manually.
+    fn listen_any_scoped<F, R: Runtime>(handle: &impl Manager<R>, handler: F) -> ListenGuard<R>
+    where
+        F: Fn(TypedEvent<Self>) + Send + 'static,
+        Self: DeserializeOwned,
+    {
+        ListenGuard {
+            id: Self::listen_any(handle, handler),
+            handle: handle.app_handle().clone(),
+        }
+    }
+
+    /// Like [`Event::listen_any`], but if this event has a cached last-emitted payload, the
+    /// `handler` is immediately invoked with it before subscribing to future emissions -
+    /// every `listen_latest` caller is brought up to date, not just the first one after an
+    /// emit. Emits only populate the cache once the first `listen_latest` call has been made
+    /// for this event or while an [`EventTransport`] is registered, so an emit made under
+    /// neither condition isn't replayed.
+    fn listen_latest<F, R: Runtime>(handle: &impl Manager<R>, handler: F) -> EventId
+    where
+        F: Fn(TypedEvent<Self>) + Send + 'static,
+        Self: DeserializeOwned,
     {
         let meta = get_meta!(handle);
 
-        handle.once_any(meta.wrap_with_plugin(Self::NAME), make_handler!(handler))
+        if let Some(value) = meta.latest.peek_latest() {
+            if let Ok(payload) = serde_json::from_value(value) {
+                // There's no real EventId for a replayed payload, so use a sentinel.
+                handler(TypedEvent { id: 0, payload });
+            }
+        }
+
+        Self::listen_any(handle, handler)
     }
 
     // Window functions
 
     fn emit(self, window: &Window<impl Runtime>) -> tauri::Result<()>
     where
-        Self: Serialize + Clone,
+        Self: Serialize,
     {
         let meta = get_meta!(window);
+        let name = meta.wrap_with_plugin(Self::NAME);
 
-        window.emit(&meta.wrap_with_plugin(Self::NAME), self)
+        if wants_value_cache(window, &meta) {
+            let value = serde_json::to_value(&self).expect("Failed to serialize event payload");
+
+            meta.latest.store(value.clone());
+            fanout_to_transports(window, &name, &value);
+
+            // Reuse the value already computed for the replay cache / transport fan-out,
+            // instead of having Tauri serialize `self` a second time internally.
+            window.emit(&name, value)
+        } else {
+            window.emit(&name, self)
+        }
     }
 
     fn listen<F>(window: &Window<impl Runtime>, handler: F) -> EventId
     where
         F: Fn(TypedEvent<Self>) + Send + 'static,
         Self: DeserializeOwned,
+    {
+        Self::listen_result(window, make_infallible_handler!(handler))
+    }
+
+    fn listen_result<F>(window: &Window<impl Runtime>, handler: F) -> EventId
+    where
+        F: Fn(Result<TypedEvent<Self>, EventError>) + Send + 'static,
+        Self: DeserializeOwned,
     {
         let meta = get_meta!(window);
 
         window.listen(meta.wrap_with_plugin(Self::NAME), make_handler!(handler))
     }
 
-    fn once<F>(window: &Window<impl Runtime>, handler: F)
+    /// Like [`Event::listen`], but returns a [`ListenGuard`] that unregisters the
+    /// listener when dropped, instead of a raw [`EventId`] that must be passed to
+    /// [`Event::unlisten`] manually.
+    fn listen_scoped<F, R: Runtime>(window: &Window<R>, handler: F) -> ListenGuard<R>
     where
-        F: FnOnce(TypedEvent<Self>) + Send + 'static,
+        F: Fn(TypedEvent<Self>) + Send + 'static,
+        Self: DeserializeOwned,
+    {
+        ListenGuard {
+            id: Self::listen(window, handler),
+            handle: window.app_handle().clone(),
+        }
+    }
+
+    /// Like [`Event::once`], but the `handler` receives a `Result` instead of having
+    /// payloads that fail to parse or deserialize silently dropped.
+    fn once_result<F>(window: &Window<impl Runtime>, handler: F)
+    where
+        F: FnOnce(Result<TypedEvent<Self>, EventError>) + Send + 'static,
         Self: DeserializeOwned,
     {
         let meta = get_meta!(window);
 
         window.once(meta.wrap_with_plugin(Self::NAME), make_handler!(handler))
     }
+
+    /// Like [`Event::listen`], but the listener is unregistered after firing once. A
+    /// payload that fails to parse or deserialize is silently dropped along with the
+    /// one-shot registration - use [`Event::once_result`] if the caller needs to know when
+    /// that happens.
+    fn once<F>(window: &Window<impl Runtime>, handler: F)
+    where
+        F: FnOnce(TypedEvent<Self>) + Send + 'static,
+        Self: DeserializeOwned,
+    {
+        Self::once_result(window, make_infallible_handler!(handler))
+    }
 }
 
 pub struct EventDataType {
@@ -206,3 +560,89 @@ macro_rules! collect_events {
       	(collection, event_data_types, type_map)
     }};
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Payload {
+        value: u32,
+    }
+
+    #[test]
+    fn parse_event_payload_rejects_invalid_json() {
+        let err = parse_event_payload::<Payload>("not json").unwrap_err();
+
+        assert!(matches!(err, EventError::PayloadParse(_)));
+    }
+
+    #[test]
+    fn parse_event_payload_rejects_mismatched_shape() {
+        let err = parse_event_payload::<Payload>(r#"{"other": true}"#).unwrap_err();
+
+        assert!(matches!(err, EventError::DeserializationError(_)));
+    }
+
+    #[test]
+    fn parse_event_payload_accepts_matching_shape() {
+        let payload = parse_event_payload::<Payload>(r#"{"value": 42}"#).unwrap();
+
+        assert_eq!(payload, Payload { value: 42 });
+    }
+
+    #[derive(Default)]
+    struct RecordingTransport(Mutex<Vec<(String, serde_json::Value)>>);
+
+    impl EventTransport for RecordingTransport {
+        fn send(&self, name: &str, payload: serde_json::Value) {
+            self.0.lock().unwrap().push((name.to_string(), payload));
+        }
+    }
+
+    #[test]
+    fn registered_transport_receives_fanned_out_payloads() {
+        let registry = EventRegistry::default();
+        assert!(!registry.has_transports());
+
+        let transport = Arc::new(RecordingTransport::default());
+        registry.register_transport(transport.clone());
+        assert!(registry.has_transports());
+
+        registry.fanout("demo-event", &serde_json::json!({ "value": 7 }));
+
+        let sent = transport.0.lock().unwrap();
+        assert_eq!(
+            sent.as_slice(),
+            &[(
+                "demo-event".to_string(),
+                serde_json::json!({ "value": 7 }),
+            )]
+        );
+    }
+
+    #[test]
+    fn event_info_skips_caching_until_replay_is_wanted() {
+        let info = EventInfo::default();
+        assert!(!info.is_replay_wanted());
+
+        // Nobody has asked for replay yet, so there's nothing cached - with no transport
+        // registered, `emit_*` would see `is_replay_wanted() == false` and skip storing entirely.
+        assert_eq!(info.peek_latest(), None);
+        assert!(info.is_replay_wanted());
+    }
+
+    #[test]
+    fn event_info_replays_to_every_caller_not_just_the_first() {
+        let info = EventInfo::default();
+        info.peek_latest();
+        info.store(serde_json::json!({ "value": 1 }));
+
+        assert_eq!(info.peek_latest(), Some(serde_json::json!({ "value": 1 })));
+        assert_eq!(info.peek_latest(), Some(serde_json::json!({ "value": 1 })));
+    }
+}